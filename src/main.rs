@@ -1,11 +1,23 @@
 use std::error::Error;
+use std::fs;
 use std::fs::read_to_string;
 use std::fs::File;
 use std::io::prelude::*;
+use std::num::ParseFloatError;
 use std::num::ParseIntError;
-use std::path::Path;
+use std::os::unix::fs::{MetadataExt, PermissionsExt};
+use std::path::{Path, PathBuf};
 
-/// `kira` allows you to set the display brightness on linux machines with intel graphics cards.
+extern "C" {
+    fn getuid() -> u32;
+}
+
+/// The current user's numeric id, used to namespace the snapshot directory per-user.
+fn current_uid() -> u32 {
+    unsafe { getuid() }
+}
+
+/// `kira` allows you to set the display brightness of any sysfs backlight or LED device.
 /// There are three input modes:
 /// ```sh
 /// $ kira
@@ -13,20 +25,34 @@ use std::path::Path;
 /// Invoking `kira` without arguments will set brightness to 100%.
 ///
 /// ```sh
-/// $ kira 55
+/// $ kira 500
+/// $ kira 55%
 /// ```
-/// Invoking `kira` with an integer between 0 and 100 will set the brightness to the percent amount of
-/// that number.
+/// Invoking `kira` with a plain number sets the raw device value directly. Append `%` to treat
+/// the number as a percentage of `max_brightness` instead.
 ///
 /// ```sh
-/// $ kira +10
-/// $ kira -22
+/// $ kira +10%
+/// $ kira -22%
+/// $ kira 100-
 /// ```
-/// Invoking `kira` with an integer prefixed with either `-` or `+` will decrease or increase by
-/// given amount in percent.
+/// A `-`/`+` prefix or suffix decreases or increases the current brightness by the given
+/// amount instead of setting it absolutely; combine with `%` for a relative percentage.
+///
+/// By default `kira` controls the first usable backlight device it finds. Use `--device <id>`
+/// to pick a specific one, `--class <backlight|leds>` to restrict the search, and `kira list` to
+/// print every discovered device along with its current value.
 ///
-/// Any change in brightness will occur stepwise with a small delay inbetween.
-/// This results in a linear smooth change of brightness over time.
+/// Percentages follow a perceptual curve by default (`--exponent 4`), so equal steps look like
+/// equal steps. Pass `--exponent 1` to get the old, purely linear mapping.
+///
+/// `kira save` remembers the current brightness and `kira restore` smoothly transitions back
+/// to it, which is handy for scripts that dim the screen on idle/lock.
+///
+/// Any change in brightness fades over `--duration <ms>` milliseconds (default 200), sampled
+/// at a fixed frame rate so the fade takes the same wall-clock time regardless of the device's
+/// `max_brightness` range. `--easing <linear|ease-in-out-cubic>` selects the curve (default
+/// `linear`).
 fn main() {
     match kira() {
         Err(error) => {
@@ -44,15 +70,42 @@ fn print_usage(writer: &mut dyn Write) -> Result<(), Box<dyn Error>> {
     write!(
         writer,
         "
-usage: kira [+-][percent]
+usage: kira [+-][value][%][+-]
+       kira list
+       kira get
+       kira save
+       kira restore
+       kira [--device <id>] [--class <backlight|leds>] [+-][value][%][+-]
+
+value is a non-negative number, fractional values are allowed. Without a
+trailing %, value is a raw device value; with a trailing %, it is a
+percentage of the device's max_brightness.
+A prefix or suffix of either - or + is allowed (e.g. `+10%`, `100-`).
+Without either, the brightness gets set to the given value.
+With a + sign, the given value gets added to current brightness.
+With a - sign, the given value gets subtracted from current brightness.
+
+`list` prints every discovered backlight/LED device with its id, class, current
+value, max value and percentage.
 
-percent must be a number between 0 and 100.
-A prefix of either - oder + is allowed.
-Without a prefix, the brightness gets set to the given percentage.
-With the + prefix, the given percentage gets added to current brightness.
-With the - prefix, the given percentage gets subtracted from current brightness.
+`get` prints the selected device's current brightness as a percentage with two
+decimal places of precision.
 
-You need permission to modify the backlight device in `/sys/class/backlight/`.
+`save` remembers the selected device's current raw brightness; `restore` smoothly
+transitions back to it. Restoring without a prior save is a no-op.
+
+`--device <id>` selects a specific device by its sysfs directory name (see `list`).
+`--class <backlight|leds>` restricts discovery to that device class.
+Without `--device`, the first usable device of the selected class(es) is used.
+
+`--exponent <K>` changes the percent<->value curve to better match perceived
+brightness (default 4). K=1 reproduces the old linear behaviour.
+
+`--duration <ms>` sets how long a brightness fade takes in milliseconds (default 200),
+regardless of how far apart the raw values are. `--easing <linear|ease-in-out-cubic>`
+selects the interpolation curve (default linear).
+
+You need permission to modify the device in `/sys/class/backlight/` or `/sys/class/leds/`.
 "
     )?;
     Ok(())
@@ -61,12 +114,12 @@ You need permission to modify the backlight device in `/sys/class/backlight/`.
 // For every error that is expected to occur in kira, this method maps a "friendly"
 // explanation text to it.
 fn match_error_to_message(error: &Box<dyn Error>) -> &'static str {
-    if error.is::<ParseIntError>() {
+    if error.is::<ParseIntError>() || error.is::<ParseFloatError>() {
         "Given percent value needs to be a number between 0 and 100."
     } else if error.is::<std::io::Error>() {
-        "Could not access the backlight device.
+        "Could not access the device.
 Does ist exist?
-Usually `/sys/class/backlight/intel_backlight/` or similar.
+Usually something under `/sys/class/backlight/` or `/sys/class/leds/`.
 Also, do you have permission to edit it?
 On most Linux distributions you need to be part of a special group (video?)."
     } else {
@@ -74,103 +127,514 @@ On most Linux distributions you need to be part of a special group (video?)."
     }
 }
 
+/// The sysfs class a [`Device`] was discovered under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeviceClass {
+    Backlight,
+    Leds,
+}
+
+impl DeviceClass {
+    fn sys_path(&self) -> &'static Path {
+        match self {
+            DeviceClass::Backlight => Path::new("/sys/class/backlight"),
+            DeviceClass::Leds => Path::new("/sys/class/leds"),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            DeviceClass::Backlight => "backlight",
+            DeviceClass::Leds => "leds",
+        }
+    }
+
+    fn parse(input: &str) -> Result<DeviceClass, Box<dyn Error>> {
+        match input {
+            "backlight" => Ok(DeviceClass::Backlight),
+            "leds" => Ok(DeviceClass::Leds),
+            other => Err(format!("Unknown device class '{}'. Expected 'backlight' or 'leds'.", other).into()),
+        }
+    }
+}
+
+/// A single brightness-capable sysfs device, e.g. `/sys/class/backlight/intel_backlight`.
+struct Device {
+    id: String,
+    class: DeviceClass,
+    path: PathBuf,
+}
+
+impl Device {
+    /// Scans `class.sys_path()` for directories that expose both `brightness` and
+    /// `max_brightness` files, returning the usable devices sorted by id.
+    fn discover(class: DeviceClass) -> Result<Vec<Device>, Box<dyn Error>> {
+        Device::discover_at(class.sys_path(), class)
+    }
+
+    /// Like [`Device::discover`], but scans `sys_path` instead of `class.sys_path()`. Split out
+    /// so tests can point it at a throwaway directory instead of the real `/sys/class/...`.
+    fn discover_at(sys_path: &Path, class: DeviceClass) -> Result<Vec<Device>, Box<dyn Error>> {
+        if !sys_path.exists() {
+            return Ok(vec![]);
+        }
+        let mut devices: Vec<Device> = fs::read_dir(sys_path)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.join("brightness").is_file() && path.join("max_brightness").is_file())
+            .map(|path| Device {
+                id: path.file_name().unwrap().to_string_lossy().into_owned(),
+                class,
+                path,
+            })
+            .collect();
+        devices.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(devices)
+    }
+
+    /// Discovers devices across both the backlight and LED classes, or just the given one.
+    fn discover_in(class: Option<DeviceClass>) -> Result<Vec<Device>, Box<dyn Error>> {
+        match class {
+            Some(class) => Device::discover(class),
+            None => {
+                let mut devices = Device::discover(DeviceClass::Backlight)?;
+                devices.extend(Device::discover(DeviceClass::Leds)?);
+                Ok(devices)
+            }
+        }
+    }
+
+    /// Finds the device with the given id, optionally restricted to one class.
+    fn find(id: &str, class: Option<DeviceClass>) -> Result<Device, Box<dyn Error>> {
+        Device::discover_in(class)?
+            .into_iter()
+            .find(|device| device.id == id)
+            .ok_or_else(|| format!("No device named '{}' found.", id).into())
+    }
+
+    /// Picks the first usable device, optionally restricted to one class.
+    fn first_usable(class: Option<DeviceClass>) -> Result<Device, Box<dyn Error>> {
+        Device::discover_in(class)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| "No usable backlight or LED device found.".into())
+    }
+
+    fn brightness_path(&self) -> PathBuf {
+        self.path.join("brightness")
+    }
+
+    fn current(&self) -> Result<u32, Box<dyn Error>> {
+        Ok(read_to_string(self.brightness_path())?.trim().parse()?)
+    }
+
+    fn max(&self) -> Result<u32, Box<dyn Error>> {
+        Ok(read_to_string(self.path.join("max_brightness"))?.trim().parse()?)
+    }
+
+    fn set(&self, value: u32) -> Result<(), Box<dyn Error>> {
+        let file = File::create(self.brightness_path())?;
+        write_to_file(&file, value)
+    }
+}
+
+/// The default curve exponent. `K=1` reproduces the old linear percent-to-value mapping;
+/// higher values make low percentages map to proportionally smaller raw values, which more
+/// closely matches how humans perceive brightness.
+const DEFAULT_EXPONENT: f32 = 4.0;
+
+/// The default wall-clock time a brightness transition takes, regardless of how many raw
+/// values lie between the current and target brightness.
+const DEFAULT_DURATION_MS: u64 = 200;
+
+/// How many frames per second a transition samples at.
+const TRANSITION_FPS: u64 = 60;
+
+/// An easing curve mapping normalized transition time `t` in `[0, 1]` to eased progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Easing {
+    Linear,
+    EaseInOutCubic,
+}
+
+impl Easing {
+    fn parse(input: &str) -> Result<Easing, Box<dyn Error>> {
+        match input {
+            "linear" => Ok(Easing::Linear),
+            "ease-in-out-cubic" => Ok(Easing::EaseInOutCubic),
+            other => Err(format!(
+                "Unknown easing '{}'. Expected 'linear' or 'ease-in-out-cubic'.",
+                other
+            )
+            .into()),
+        }
+    }
+
+    fn apply(&self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// The parsed command line, split into device-selection options and the remaining positional
+/// arguments (the brightness input, if any).
+struct Options {
+    device: Option<String>,
+    class: Option<DeviceClass>,
+    list: bool,
+    get: bool,
+    save: bool,
+    restore: bool,
+    exponent: f32,
+    duration_ms: u64,
+    easing: Easing,
+}
+
+fn parse_options(args: &[String]) -> Result<(Options, Vec<String>), Box<dyn Error>> {
+    let mut options = Options {
+        device: None,
+        class: None,
+        list: false,
+        get: false,
+        save: false,
+        restore: false,
+        exponent: DEFAULT_EXPONENT,
+        duration_ms: DEFAULT_DURATION_MS,
+        easing: Easing::Linear,
+    };
+    let mut rest = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "list" => options.list = true,
+            "get" => options.get = true,
+            "save" | "--save" => options.save = true,
+            "restore" | "--restore" => options.restore = true,
+            "--device" => {
+                let value = args.next().ok_or("Missing value for --device")?;
+                options.device = Some(value.clone());
+            }
+            "--class" => {
+                let value = args.next().ok_or("Missing value for --class")?;
+                options.class = Some(DeviceClass::parse(value)?);
+            }
+            "--duration" => {
+                let value = args.next().ok_or("Missing value for --duration")?;
+                options.duration_ms = value.parse()?;
+            }
+            "--easing" => {
+                let value = args.next().ok_or("Missing value for --easing")?;
+                options.easing = Easing::parse(value)?;
+            }
+            "--exponent" => {
+                let value = args.next().ok_or("Missing value for --exponent")?;
+                options.exponent = value.parse()?;
+            }
+            other => rest.push(other.to_string()),
+        }
+    }
+    Ok((options, rest))
+}
+
+/// Converts a perceptual percentage (0-100) into a raw device value, using the same curve as
+/// brightnessctl: `val = p^K * max * 100^-K`. Rounds down and does not clamp.
+fn percent_to_raw(percent: f32, max: u32, exponent: f32) -> u32 {
+    let value = percent.powf(exponent) * max as f32 * 100f32.powf(-exponent);
+    value.floor() as u32
+}
+
+/// The inverse of [`percent_to_raw`]: recovers the perceptual percentage a raw value displays as.
+fn raw_to_percent(value: u32, max: u32, exponent: f32) -> f32 {
+    (100f32.powf(exponent) * value as f32 / max as f32).powf(1.0 / exponent)
+}
+
+fn list_devices(class: Option<DeviceClass>, exponent: f32) -> Result<(), Box<dyn Error>> {
+    let devices = Device::discover_in(class)?;
+    if devices.is_empty() {
+        println!("No backlight or LED devices found.");
+        return Ok(());
+    }
+    for device in &devices {
+        let current = device.current()?;
+        let max = device.max()?;
+        let percent = raw_to_percent(current, max, exponent);
+        println!(
+            "{:<24} {:<10} {:>8}/{:<8} {:>6.2}%",
+            device.id,
+            device.class.as_str(),
+            current,
+            max,
+            percent
+        );
+    }
+    Ok(())
+}
+
+/// Directory snapshots are stored under, namespaced per-user since the path is predictable
+/// and must not be shared with (or plantable by) other local users.
+fn snapshot_dir() -> PathBuf {
+    if let Ok(runtime_dir) = std::env::var("XDG_RUNTIME_DIR") {
+        if !runtime_dir.is_empty() {
+            return Path::new(&runtime_dir).join("kira");
+        }
+    }
+    std::env::temp_dir().join(format!("kira-{}", current_uid()))
+}
+
+fn snapshot_path(device_id: &str) -> PathBuf {
+    snapshot_dir().join(device_id)
+}
+
+/// Creates `dir` (private, `0700`) if missing. If it already exists, refuses to use it unless
+/// it is a real directory owned by the current user and not group/world-writable, so another
+/// local user can't plant a pre-existing `/tmp`-adjacent directory (or a symlink at the
+/// device-id path inside it) to make us write through to an arbitrary file.
+fn ensure_private_snapshot_dir(dir: &Path) -> Result<(), Box<dyn Error>> {
+    match fs::symlink_metadata(dir) {
+        Ok(metadata) => {
+            if metadata.file_type().is_symlink() || !metadata.is_dir() {
+                return Err(format!("Refusing to use '{}': not a plain directory.", dir.display()).into());
+            }
+            if metadata.uid() != current_uid() {
+                return Err(format!("Refusing to use '{}': not owned by the current user.", dir.display()).into());
+            }
+            if metadata.permissions().mode() & 0o022 != 0 {
+                return Err(format!("Refusing to use '{}': group- or world-writable.", dir.display()).into());
+            }
+            Ok(())
+        }
+        Err(ref error) if error.kind() == std::io::ErrorKind::NotFound => {
+            fs::create_dir_all(dir)?;
+            fs::set_permissions(dir, fs::Permissions::from_mode(0o700))?;
+            Ok(())
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Writes `contents` to `path`, refusing to follow a symlink planted at that path.
+fn write_snapshot(path: &Path, contents: &str) -> Result<(), Box<dyn Error>> {
+    if let Ok(metadata) = fs::symlink_metadata(path) {
+        if metadata.file_type().is_symlink() {
+            return Err(format!("Refusing to write '{}': it is a symlink.", path.display()).into());
+        }
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Reads `device`'s current raw brightness and writes it to its snapshot file.
+fn save_brightness(device: &Device) -> Result<(), Box<dyn Error>> {
+    let dir = snapshot_dir();
+    ensure_private_snapshot_dir(&dir)?;
+    write_snapshot(&snapshot_path(&device.id), &device.current()?.to_string())
+}
+
+/// Reads back `device`'s snapshot file, if any. A missing file is a no-op, not an error. A
+/// symlink at the snapshot path is refused rather than followed.
+fn restore_brightness(device: &Device) -> Result<Option<u32>, Box<dyn Error>> {
+    let path = snapshot_path(&device.id);
+    let metadata = match fs::symlink_metadata(&path) {
+        Ok(metadata) => metadata,
+        Err(ref error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+    if metadata.file_type().is_symlink() {
+        return Err(format!("Refusing to read '{}': it is a symlink.", path.display()).into());
+    }
+    Ok(Some(read_to_string(path)?.trim().parse()?))
+}
+
+/// Transitions `device` from `current` to `target` over `duration_ms` wall-clock milliseconds,
+/// sampling `eased_value` at [`TRANSITION_FPS`] and writing only when the rounded value changes.
+/// This keeps the transition time device-independent, unlike stepping through every raw value.
+fn transition(
+    device: &Device,
+    current: u32,
+    target: u32,
+    duration_ms: u64,
+    easing: Easing,
+) -> Result<(), Box<dyn Error>> {
+    if current == target {
+        return Ok(());
+    }
+    let frame_count = ((duration_ms * TRANSITION_FPS) / 1000).max(1);
+    let frame_delay = std::time::Duration::from_millis(duration_ms) / frame_count as u32;
+    let mut last_written = current;
+    for frame in 0..=frame_count {
+        let t = frame as f32 / frame_count as f32;
+        let value = eased_value(current, target, easing.apply(t));
+        if value != last_written {
+            device.set(value)?;
+            last_written = value;
+        }
+        if frame < frame_count {
+            std::thread::sleep(frame_delay);
+        }
+    }
+    Ok(())
+}
+
+/// Interpolates between `start` and `target` at eased progress `t` in `[0, 1]`, rounding to the
+/// nearest raw value.
+fn eased_value(start: u32, target: u32, t: f32) -> u32 {
+    (start as f32 + (target as f32 - start as f32) * t).round() as u32
+}
+
 fn kira() -> Result<(), Box<dyn Error>> {
-    let backlight = Path::new("/sys/class/backlight/intel_backlight/");
-    let brightness = backlight.join("brightness");
-    let max_brightness_value: u16 = read_to_string(backlight.join("max_brightness"))?
-        .trim()
-        .parse()?;
-    let min_brightness_value: u16 = 0;
-    let current_value: u16 = read_to_string(&brightness)?.trim().parse()?;
-    let args: Vec<String> = std::env::args().collect();
-    let target: u16 = if args.len() > 1 {
-        let (signum, percent) = parse_input_as_percent(&args[1])?;
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (options, rest) = parse_options(&args)?;
+
+    if options.list {
+        return list_devices(options.class, options.exponent);
+    }
+
+    let device = match &options.device {
+        Some(id) => Device::find(id, options.class)?,
+        None => Device::first_usable(options.class)?,
+    };
+
+    if options.get {
+        let current = device.current()?;
+        let max = device.max()?;
+        println!("{:.2}%", raw_to_percent(current, max, options.exponent));
+        return Ok(());
+    }
+
+    if options.save {
+        return save_brightness(&device);
+    }
+
+    if options.restore {
+        return match restore_brightness(&device)? {
+            Some(target) => transition(
+                &device,
+                device.current()?,
+                target.clamp(0, device.max()?),
+                options.duration_ms,
+                options.easing,
+            ),
+            None => Ok(()),
+        };
+    }
+
+    let max_brightness_value = device.max()?;
+    let min_brightness_value: u32 = 0;
+    let current_value = device.current()?;
+    let target: u32 = if let Some(input) = rest.first() {
+        let (signum, kind, magnitude) = parse_input(input)?;
         calculate_target_value(
             signum,
-            percent,
+            kind,
+            magnitude,
             current_value,
             max_brightness_value,
             min_brightness_value,
+            options.exponent,
         )
     } else {
         max_brightness_value
     };
-    let current_brightness_value: u16 = read_to_string(&brightness)?.trim().parse()?;
-    let current_brightness_file = File::create(&brightness)?;
-    if target > current_brightness_value {
-        for b in current_brightness_value..=target {
-            write_to_file_and_wait(&current_brightness_file, b, 100)?;
-        }
-    } else if target < current_brightness_value {
-        for b in (target..=current_brightness_value).rev() {
-            write_to_file_and_wait(&current_brightness_file, b, 100)?;
-        }
-    }
-    Ok(())
+
+    transition(
+        &device,
+        device.current()?,
+        target,
+        options.duration_ms,
+        options.easing,
+    )
 }
 
-fn write_to_file_and_wait(mut file: &File, value: u16, nanos: u64) -> Result<(), Box<dyn Error>> {
+fn write_to_file(mut file: &File, value: u32) -> Result<(), Box<dyn Error>> {
     file.write_all(&value.to_string().as_bytes())?;
     file.sync_data()?;
-    std::thread::sleep(std::time::Duration::from_nanos(nanos));
     Ok(())
 }
 
-fn parse_input_as_percent(input: &str) -> Result<(Option<bool>, u8), Box<dyn Error>> {
-    if input.starts_with('+') {
-        Ok((Some(true), input[1..].parse()?))
-    } else if input.starts_with('-') {
-        Ok((Some(false), input[1..].parse()?))
-    } else {
-        Ok((None, input[..].parse()?))
+/// Whether a parsed input magnitude is a raw device value or a percentage of `max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ValueKind {
+    Raw,
+    Percent,
+}
+
+/// Parses a value/delta argument like `500`, `50%`, `+10%`, `100-` or `50%-`. A leading or
+/// trailing `+`/`-` selects a relative delta (added to or subtracted from the current
+/// brightness); without either, the magnitude is an absolute target. A trailing `%` marks the
+/// magnitude as a percentage of `max`; without it, the magnitude is a raw device value.
+fn parse_input(input: &str) -> Result<(Option<bool>, ValueKind, f32), Box<dyn Error>> {
+    let mut remainder = input;
+    let mut signum = None;
+    if let Some(stripped) = remainder.strip_prefix('+') {
+        signum = Some(true);
+        remainder = stripped;
+    } else if let Some(stripped) = remainder.strip_prefix('-') {
+        signum = Some(false);
+        remainder = stripped;
+    }
+    if let Some(stripped) = remainder.strip_suffix('+') {
+        signum = Some(true);
+        remainder = stripped;
+    } else if let Some(stripped) = remainder.strip_suffix('-') {
+        signum = Some(false);
+        remainder = stripped;
     }
+    let (kind, magnitude) = match remainder.strip_suffix('%') {
+        Some(stripped) => (ValueKind::Percent, stripped),
+        None => (ValueKind::Raw, remainder),
+    };
+    Ok((signum, kind, magnitude.parse()?))
 }
 
-/// Calculates the actual brigthness value, given the min-max-range and percent value.
-/// If a signum is given, the percentage value will be added//subtracted to the current
+/// Calculates the actual brigthness value, given the min-max-range and a parsed input.
+/// If a signum is given, the magnitude will be added//subtracted to the current
 /// brightness value.
 /// In any case, this method returns the absolute target brigthness value.
-/// signum: Relativizes the `percent` value. `None` means the given `percent` value is
-/// meant to be an absolute target value. `Some(true)` means the target is the current
-/// value added to the percentage. `Some(false)` means the target is the subtraction of
-/// the current value and the given percentage.
-/// percent: Percentage of wanted target value. Expected to be between 0 - 100.
+/// signum: Relativizes `magnitude`. `None` means `magnitude` is an absolute target value.
+/// `Some(true)` means the target is the current value added to `magnitude`. `Some(false)`
+/// means the target is the subtraction of the current value and `magnitude`.
+/// kind: whether `magnitude` is a raw device value or a percentage of `max`.
+/// magnitude: the parsed input value, expected to be non-negative. Fractional percentages are
+/// allowed for finer-grained control on panels with a large `max_brightness`; fractional raw
+/// values are rounded down.
 /// current: the current absolute brightness value (not a percentage).
 /// max: the maximum absolute brightness value.
 /// min: the minimum absolute brigthness value.
+/// exponent: the perceptual curve exponent `K` used to map between percent and raw value
+/// (see [`percent_to_raw`]/[`raw_to_percent`]). `K=1` is linear.
 fn calculate_target_value(
     signum: Option<bool>,
-    percent: u8,
-    current: u16,
-    max: u16,
-    min: u16,
-) -> u16 {
-    let value: u16 = (max as f32 * percent as f32 / 100.0) as u16;
-    match signum {
-        Some(positive) => {
-            let new_value = if positive {
-                current.saturating_add(value)
+    kind: ValueKind,
+    magnitude: f32,
+    current: u32,
+    max: u32,
+    min: u32,
+    exponent: f32,
+) -> u32 {
+    let target = match (signum, kind) {
+        (Some(positive), ValueKind::Percent) => {
+            let current_percent = raw_to_percent(current, max, exponent);
+            let new_percent = if positive {
+                current_percent + magnitude
             } else {
-                current.saturating_sub(value)
+                current_percent - magnitude
             };
-            if new_value >= max {
-                max
-            } else if new_value <= min {
-                min
-            } else {
-                new_value
-            }
-        }
-        None => {
-            if value >= max {
-                max
-            } else if value <= min {
-                min
-            } else {
-                value
-            }
+            percent_to_raw(new_percent.clamp(0.0, 100.0), max, exponent)
         }
-    }
+        (None, ValueKind::Percent) => percent_to_raw(magnitude, max, exponent),
+        (Some(true), ValueKind::Raw) => current.saturating_add(magnitude.floor() as u32),
+        (Some(false), ValueKind::Raw) => current.saturating_sub(magnitude.floor() as u32),
+        (None, ValueKind::Raw) => magnitude.floor() as u32,
+    };
+    target.clamp(min, max)
 }
 
 #[cfg(test)]
@@ -193,129 +657,364 @@ mod tests {
     }
 
     #[test]
-    fn checkcalculated_target_values() {
-        assert_eq!(calculate_target_value(None, 22, 0, 100, 0), 22u16);
-        assert_eq!(calculate_target_value(None, 77, 0, 4438, 0), 3417u16);
-        assert_eq!(calculate_target_value(None, 0, 0, 100, 0), 0u16);
-        assert_eq!(calculate_target_value(None, 100, 0, 100, 0), 100u16);
-        assert_eq!(calculate_target_value(None, 200, 0, 100, 0), 100u16);
-        assert_eq!(calculate_target_value(None, 22, 0, 100, 50), 50u16);
-
-        assert_eq!(calculate_target_value(Some(true), 22, 0, 100, 0), 22u16);
-        assert_eq!(calculate_target_value(Some(true), 22, 10, 100, 0), 32u16);
-        assert_eq!(calculate_target_value(Some(true), 22, 80, 100, 0), 100u16);
-        assert_eq!(calculate_target_value(Some(true), 122, 80, 100, 0), 100u16);
-        assert_eq!(calculate_target_value(Some(true), 200, 80, 100, 0), 100u16);
-        assert_eq!(calculate_target_value(Some(true), 1, 100, 100, 0), 100u16);
-        assert_eq!(calculate_target_value(Some(true), 0, 0, 100, 0), 0u16);
-
-        assert_eq!(calculate_target_value(Some(false), 22, 0, 100, 0), 0u16);
-        assert_eq!(calculate_target_value(Some(false), 22, 50, 100, 0), 28u16);
-        assert_eq!(calculate_target_value(Some(false), 22, 55, 100, 50), 50u16);
-        assert_eq!(calculate_target_value(Some(false), 22, 88, 100, 0), 66u16);
-
-        assert_eq!(calculate_target_value(None, 22, 0, 1000, 0), 220u16);
-        assert_eq!(calculate_target_value(None, 0, 0, 1000, 0), 0u16);
-        assert_eq!(calculate_target_value(None, 100, 0, 1000, 0), 1000u16);
-        assert_eq!(calculate_target_value(None, 110, 0, 1000, 0), 1000u16);
-        assert_eq!(calculate_target_value(None, 1, 0, 10000, 0), 100u16);
-        assert_eq!(calculate_target_value(None, 33, 0, 100, 0), 33u16);
-        assert_eq!(calculate_target_value(None, 73, 0, 14687, 999), 10721u16);
+    fn parse_options_separates_flags_from_positional_args() {
+        let args: Vec<String> = vec!["--device".into(), "acpi_video0".into(), "+10".into()];
+        let (options, rest) = parse_options(&args).unwrap();
+        assert_eq!(options.device, Some("acpi_video0".to_string()));
+        assert_eq!(rest, vec!["+10".to_string()]);
     }
 
     #[test]
-    fn check_expected_input_values() {
-        let (signum, percent) = parse_input_as_percent("+10").unwrap();
-        assert_eq!(signum, Some(true));
-        assert_eq!(percent, 10u8);
+    fn parse_options_recognizes_save_and_restore() {
+        let (options, _) = parse_options(&["save".to_string()]).unwrap();
+        assert!(options.save);
 
-        let (signum, percent) = parse_input_as_percent("+0").unwrap();
-        assert_eq!(signum, Some(true));
-        assert_eq!(percent, 0u8);
+        let (options, _) = parse_options(&["--restore".to_string()]).unwrap();
+        assert!(options.restore);
+    }
 
-        let (signum, percent) = parse_input_as_percent("+100").unwrap();
-        assert_eq!(signum, Some(true));
-        assert_eq!(percent, 100u8);
+    #[test]
+    fn parse_options_reads_duration_and_easing() {
+        let args: Vec<String> = vec![
+            "--duration".into(),
+            "500".into(),
+            "--easing".into(),
+            "ease-in-out-cubic".into(),
+        ];
+        let (options, _) = parse_options(&args).unwrap();
+        assert_eq!(options.duration_ms, 500);
+        assert_eq!(options.easing, Easing::EaseInOutCubic);
+    }
 
-        let (signum, percent) = parse_input_as_percent("+44").unwrap();
-        assert_eq!(signum, Some(true));
-        assert_eq!(percent, 44u8);
+    #[test]
+    fn parse_options_defaults_duration_and_easing() {
+        let (options, _) = parse_options(&[]).unwrap();
+        assert_eq!(options.duration_ms, DEFAULT_DURATION_MS);
+        assert_eq!(options.easing, Easing::Linear);
+    }
 
-        let (signum, percent) = parse_input_as_percent("-10").unwrap();
-        assert_eq!(signum, Some(false));
-        assert_eq!(percent, 10u8);
+    #[test]
+    fn easing_parse_rejects_unknown_curves() {
+        assert!(Easing::parse("bounce").is_err());
+    }
 
-        let (signum, percent) = parse_input_as_percent("-200").unwrap();
-        assert_eq!(signum, Some(false));
-        assert_eq!(percent, 200u8);
+    #[test]
+    fn easing_linear_is_identity() {
+        assert_eq!(Easing::Linear.apply(0.0), 0.0);
+        assert_eq!(Easing::Linear.apply(0.5), 0.5);
+        assert_eq!(Easing::Linear.apply(1.0), 1.0);
+    }
 
-        let (signum, percent) = parse_input_as_percent("+250").unwrap();
-        assert_eq!(signum, Some(true));
-        assert_eq!(percent, 250u8);
+    #[test]
+    fn easing_ease_in_out_cubic_hits_its_anchor_points() {
+        assert_eq!(Easing::EaseInOutCubic.apply(0.0), 0.0);
+        assert_eq!(Easing::EaseInOutCubic.apply(1.0), 1.0);
+        assert!((Easing::EaseInOutCubic.apply(0.5) - 0.5).abs() < 1e-6);
+    }
 
-        let (signum, percent) = parse_input_as_percent("244").unwrap();
-        assert_eq!(signum, None);
-        assert_eq!(percent, 244u8);
+    #[test]
+    fn eased_value_interpolates_and_rounds() {
+        assert_eq!(eased_value(0, 100, 0.0), 0);
+        assert_eq!(eased_value(0, 100, 1.0), 100);
+        assert_eq!(eased_value(0, 100, 0.5), 50);
+    }
 
-        let (signum, percent) = parse_input_as_percent("10").unwrap();
-        assert_eq!(signum, None);
-        assert_eq!(percent, 10u8);
+    #[test]
+    fn restore_brightness_is_a_no_op_without_a_prior_save() {
+        let device = Device {
+            id: "kira-test-device-that-was-never-saved".to_string(),
+            class: DeviceClass::Backlight,
+            path: PathBuf::new(),
+        };
+        assert_eq!(restore_brightness(&device).unwrap(), None);
+    }
 
-        let (signum, percent) = parse_input_as_percent("-100").unwrap();
-        assert_eq!(signum, Some(false));
-        assert_eq!(percent, 100u8);
+    #[test]
+    fn save_then_restore_brightness_round_trips_the_saved_value() {
+        let dir = std::env::temp_dir().join("kira-test-save-then-restore-round-trips");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("brightness"), "42").unwrap();
+        fs::write(dir.join("max_brightness"), "100").unwrap();
+        let device = Device {
+            id: "kira-test-save-then-restore-round-trips".to_string(),
+            class: DeviceClass::Backlight,
+            path: dir.clone(),
+        };
 
-        let (signum, percent) = parse_input_as_percent("100").unwrap();
-        assert_eq!(signum, None);
-        assert_eq!(percent, 100u8);
+        save_brightness(&device).unwrap();
+        fs::write(dir.join("brightness"), "7").unwrap();
+        assert_eq!(restore_brightness(&device).unwrap(), Some(42));
+
+        fs::remove_file(&snapshot_path(&device.id)).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn write_snapshot_refuses_to_follow_a_symlink() {
+        let dir = std::env::temp_dir().join("kira-test-write-snapshot-refuses-symlink");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let target = dir.join("outside-file");
+        fs::write(&target, "untouched").unwrap();
+        let link = dir.join("device-id");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let result = write_snapshot(&link, "123");
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&target).unwrap(), "untouched");
 
-        let (signum, percent) = parse_input_as_percent("35").unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn ensure_private_snapshot_dir_refuses_a_symlinked_directory() {
+        let base = std::env::temp_dir().join("kira-test-ensure-private-dir-refuses-symlink");
+        let _ = fs::remove_dir_all(&base);
+        fs::create_dir_all(&base).unwrap();
+        let real_dir = base.join("real");
+        fs::create_dir_all(&real_dir).unwrap();
+        let link = base.join("snapshot-dir");
+        std::os::unix::fs::symlink(&real_dir, &link).unwrap();
+
+        assert!(ensure_private_snapshot_dir(&link).is_err());
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn ensure_private_snapshot_dir_creates_a_private_directory_when_missing() {
+        let dir = std::env::temp_dir().join("kira-test-ensure-private-dir-creates");
+        let _ = fs::remove_dir_all(&dir);
+
+        ensure_private_snapshot_dir(&dir).unwrap();
+        let mode = fs::symlink_metadata(&dir).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o700);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn parse_options_recognizes_class_and_list() {
+        let args: Vec<String> = vec!["list".into(), "--class".into(), "leds".into()];
+        let (options, rest) = parse_options(&args).unwrap();
+        assert!(options.list);
+        assert_eq!(options.class, Some(DeviceClass::Leds));
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn device_class_parse_rejects_unknown_names() {
+        assert!(DeviceClass::parse("keyboard").is_err());
+    }
+
+    #[test]
+    fn discover_at_only_returns_devices_under_the_given_path() {
+        let dir = std::env::temp_dir().join("kira-test-discover-at-only-returns");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("usable")).unwrap();
+        fs::write(dir.join("usable").join("brightness"), "5").unwrap();
+        fs::write(dir.join("usable").join("max_brightness"), "10").unwrap();
+        fs::create_dir_all(dir.join("not-a-device")).unwrap();
+
+        let devices = Device::discover_at(&dir, DeviceClass::Leds).unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].id, "usable");
+        assert_eq!(devices[0].class, DeviceClass::Leds);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn discover_in_restricted_to_one_class_does_not_see_devices_of_the_other() {
+        // `discover_in(Some(class))` must only ever call `Device::discover` for that one
+        // class, never both -- this is the seam `list_devices` relies on for `--class`.
+        let backlight = Device::discover_in(Some(DeviceClass::Backlight)).unwrap();
+        assert!(backlight.iter().all(|d| d.class == DeviceClass::Backlight));
+
+        let leds = Device::discover_in(Some(DeviceClass::Leds)).unwrap();
+        assert!(leds.iter().all(|d| d.class == DeviceClass::Leds));
+    }
+
+    #[test]
+    fn parse_options_reads_exponent() {
+        let args: Vec<String> = vec!["--exponent".into(), "2".into(), "50".into()];
+        let (options, rest) = parse_options(&args).unwrap();
+        assert_eq!(options.exponent, 2.0);
+        assert_eq!(rest, vec!["50".to_string()]);
+    }
+
+    #[test]
+    fn parse_options_defaults_exponent() {
+        let (options, _) = parse_options(&[]).unwrap();
+        assert_eq!(options.exponent, DEFAULT_EXPONENT);
+    }
+
+    #[test]
+    fn percent_to_raw_and_back_roundtrip_with_exponent_one() {
+        assert_eq!(percent_to_raw(50.0, 1000, 1.0), 500);
+        assert_eq!(raw_to_percent(500, 1000, 1.0), 50.0);
+    }
+
+    #[test]
+    fn percent_to_raw_curves_low_percentages_down_for_higher_exponents() {
+        // K=4 should push the same percentage to a proportionally smaller raw value than K=1.
+        let linear = percent_to_raw(10.0, 1000, 1.0);
+        let curved = percent_to_raw(10.0, 1000, 4.0);
+        assert!(curved < linear);
+        assert_eq!(percent_to_raw(100.0, 1000, 4.0), 1000);
+        assert_eq!(percent_to_raw(0.0, 1000, 4.0), 0);
+    }
+
+    #[test]
+    fn checkcalculated_target_values() {
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 22.0, 0, 100, 0, 1.0), 22u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 77.0, 0, 4438, 0, 1.0), 3417u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 0.0, 0, 100, 0, 1.0), 0u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 100.0, 0, 100, 0, 1.0), 100u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 200.0, 0, 100, 0, 1.0), 100u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 22.0, 0, 100, 50, 1.0), 50u32);
+
+        assert_eq!(calculate_target_value(Some(true), ValueKind::Percent, 22.0, 0, 100, 0, 1.0), 22u32);
+        assert_eq!(calculate_target_value(Some(true), ValueKind::Percent, 22.0, 10, 100, 0, 1.0), 32u32);
+        assert_eq!(calculate_target_value(Some(true), ValueKind::Percent, 22.0, 80, 100, 0, 1.0), 100u32);
+        assert_eq!(calculate_target_value(Some(true), ValueKind::Percent, 122.0, 80, 100, 0, 1.0), 100u32);
+        assert_eq!(calculate_target_value(Some(true), ValueKind::Percent, 200.0, 80, 100, 0, 1.0), 100u32);
+        assert_eq!(calculate_target_value(Some(true), ValueKind::Percent, 1.0, 100, 100, 0, 1.0), 100u32);
+        assert_eq!(calculate_target_value(Some(true), ValueKind::Percent, 0.0, 0, 100, 0, 1.0), 0u32);
+
+        assert_eq!(calculate_target_value(Some(false), ValueKind::Percent, 22.0, 0, 100, 0, 1.0), 0u32);
+        assert_eq!(calculate_target_value(Some(false), ValueKind::Percent, 22.0, 50, 100, 0, 1.0), 28u32);
+        assert_eq!(calculate_target_value(Some(false), ValueKind::Percent, 22.0, 55, 100, 50, 1.0), 50u32);
+        assert_eq!(calculate_target_value(Some(false), ValueKind::Percent, 22.0, 88, 100, 0, 1.0), 66u32);
+
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 22.0, 0, 1000, 0, 1.0), 220u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 0.0, 0, 1000, 0, 1.0), 0u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 100.0, 0, 1000, 0, 1.0), 1000u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 110.0, 0, 1000, 0, 1.0), 1000u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 1.0, 0, 10000, 0, 1.0), 100u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 33.0, 0, 100, 0, 1.0), 33u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Percent, 73.0, 0, 14687, 999, 1.0), 10721u32);
+    }
+
+    #[test]
+    fn checkcalculated_target_values_for_raw_kind() {
+        assert_eq!(calculate_target_value(None, ValueKind::Raw, 500.0, 0, 1000, 0, 1.0), 500u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Raw, 2000.0, 0, 1000, 0, 1.0), 1000u32);
+        assert_eq!(calculate_target_value(Some(true), ValueKind::Raw, 100.0, 400, 1000, 0, 1.0), 500u32);
+        assert_eq!(calculate_target_value(Some(false), ValueKind::Raw, 100.0, 400, 1000, 0, 1.0), 300u32);
+        assert_eq!(calculate_target_value(Some(false), ValueKind::Raw, 100.0, 50, 1000, 0, 1.0), 0u32);
+        assert_eq!(calculate_target_value(None, ValueKind::Raw, 50.5, 0, 1000, 0, 1.0), 50u32);
+    }
+
+    #[test]
+    fn handles_max_brightness_values_that_overflow_u16() {
+        // Regression test: some panels report a `max_brightness` well above 65535 (u16::MAX),
+        // e.g. 96000. Parsing, the percent curve, and saturating raw deltas all need to hold up.
+        let max: u32 = 96000;
+        assert_eq!("96000".parse::<u32>().unwrap(), max);
+        assert_eq!(percent_to_raw(50.0, max, 1.0), 48000);
+        assert_eq!(raw_to_percent(48000, max, 1.0), 50.0);
+        assert_eq!(
+            calculate_target_value(None, ValueKind::Percent, 50.0, 0, max, 0, 1.0),
+            48000u32
+        );
+        assert_eq!(
+            calculate_target_value(Some(true), ValueKind::Raw, 1000.0, 95500, max, 0, 1.0),
+            max
+        );
+    }
+
+    #[test]
+    fn check_expected_input_values() {
+        let (signum, kind, magnitude) = parse_input("+10%").unwrap();
+        assert_eq!(signum, Some(true));
+        assert_eq!(kind, ValueKind::Percent);
+        assert_eq!(magnitude, 10f32);
+
+        let (signum, kind, magnitude) = parse_input("-200%").unwrap();
+        assert_eq!(signum, Some(false));
+        assert_eq!(kind, ValueKind::Percent);
+        assert_eq!(magnitude, 200f32);
+
+        let (signum, kind, magnitude) = parse_input("244%").unwrap();
         assert_eq!(signum, None);
-        assert_eq!(percent, 35u8);
+        assert_eq!(kind, ValueKind::Percent);
+        assert_eq!(magnitude, 244f32);
 
-        let (signum, percent) = parse_input_as_percent("255").unwrap();
+        let (signum, kind, magnitude) = parse_input("35%").unwrap();
         assert_eq!(signum, None);
-        assert_eq!(percent, 255u8);
+        assert_eq!(kind, ValueKind::Percent);
+        assert_eq!(magnitude, 35f32);
     }
 
     #[test]
-    #[should_panic]
-    fn check_larger_than_u8_error() {
-        parse_input_as_percent("300").unwrap();
+    fn check_fractional_percent_values() {
+        let (signum, kind, magnitude) = parse_input("50.5%").unwrap();
+        assert_eq!(signum, None);
+        assert_eq!(kind, ValueKind::Percent);
+        assert_eq!(magnitude, 50.5f32);
+
+        let (signum, kind, magnitude) = parse_input("+12.5%").unwrap();
+        assert_eq!(signum, Some(true));
+        assert_eq!(kind, ValueKind::Percent);
+        assert_eq!(magnitude, 12.5f32);
+
+        let (signum, kind, magnitude) = parse_input("-0.25%").unwrap();
+        assert_eq!(signum, Some(false));
+        assert_eq!(kind, ValueKind::Percent);
+        assert_eq!(magnitude, 0.25f32);
     }
+
     #[test]
-    #[should_panic]
-    fn check_larger_than_u8_positive_error() {
-        parse_input_as_percent("+300").unwrap();
+    fn check_raw_values_default_when_no_percent_suffix() {
+        let (signum, kind, magnitude) = parse_input("500").unwrap();
+        assert_eq!(signum, None);
+        assert_eq!(kind, ValueKind::Raw);
+        assert_eq!(magnitude, 500f32);
+
+        let (signum, kind, magnitude) = parse_input("+10").unwrap();
+        assert_eq!(signum, Some(true));
+        assert_eq!(kind, ValueKind::Raw);
+        assert_eq!(magnitude, 10f32);
+
+        let (signum, kind, magnitude) = parse_input("100-").unwrap();
+        assert_eq!(signum, Some(false));
+        assert_eq!(kind, ValueKind::Raw);
+        assert_eq!(magnitude, 100f32);
     }
+
     #[test]
-    #[should_panic]
-    fn check_larger_than_u8_negative_error() {
-        parse_input_as_percent("-300").unwrap();
+    fn check_trailing_sign_mirrors_leading_sign() {
+        let (signum, kind, magnitude) = parse_input("50%-").unwrap();
+        assert_eq!(signum, Some(false));
+        assert_eq!(kind, ValueKind::Percent);
+        assert_eq!(magnitude, 50f32);
+
+        let (signum, kind, magnitude) = parse_input("50%+").unwrap();
+        assert_eq!(signum, Some(true));
+        assert_eq!(kind, ValueKind::Percent);
+        assert_eq!(magnitude, 50f32);
     }
+
     #[test]
     #[should_panic]
     fn check_empty_error() {
-        parse_input_as_percent("").unwrap();
-    }
-    #[test]
-    #[should_panic]
-    fn check_very_larger_than_u8_error() {
-        parse_input_as_percent("42934632").unwrap();
+        parse_input("").unwrap();
     }
     #[test]
     #[should_panic]
     fn check_words_error() {
-        parse_input_as_percent("not a number").unwrap();
+        parse_input("not a number").unwrap();
     }
     #[test]
     #[should_panic]
     fn check_binary_error() {
-        parse_input_as_percent("0x110010").unwrap();
+        parse_input("0x110010").unwrap();
     }
     #[test]
     #[should_panic]
     fn check_number_as_word_error() {
-        parse_input_as_percent("five").unwrap();
+        parse_input("five").unwrap();
     }
 }